@@ -1,4 +1,7 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str;
 
 use binding::dpiTimestamp;
@@ -6,12 +9,72 @@ use util::Scanner;
 use OracleType;
 use ParseError;
 
+/// Number of days in each month in a non-leap year, indexed by `month - 1`.
+const DAYS_IN_MONTH: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Reads at most `max` consecutive decimal digits from `scanner`, returning
+/// their value, or `None` if no digit is present. Unlike `Scanner::read_digits`
+/// this is width-bounded so adjacent numeric fields (e.g. `%Y%m%d`) can be
+/// parsed without the first specifier swallowing the whole digit run.
+fn read_digits_max(scanner: &mut Scanner, max: u32) -> Option<u64> {
+    let mut value: u64 = 0;
+    let mut count = 0;
+    while count < max {
+        match scanner.char() {
+            Some(c) if c.is_digit(10) => {
+                value = value * 10 + c.to_digit(10).unwrap() as u64;
+                scanner.next();
+                count += 1;
+            },
+            _ => break,
+        }
+    }
+    if count == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Proleptic Gregorian leap-year test.
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Number of days from the civil date `(year, month, day)` to the Unix epoch
+/// (1970-01-01), using the standard civil-to-days algorithm.
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year as i64 - 1 } else { year as i64 };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Inverse of [`days_from_civil`]: turns a day number relative to the Unix
+/// epoch back into a civil `(year, month, day)`.
+fn civil_from_days(z: i64) -> (i32, u32, u32) {
+    let z = z + 719468;
+    let era = (if z >= 0 { z } else { z - 146096 }) / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if m <= 2 { y + 1 } else { y };
+    (year as i32, m as u32, d as u32)
+}
+
 /// Timestamp type corresponding to Oracle datetime types: `DATE`, `TIMESTAMP`,
 /// `TIMESTAMP WITH TIME ZONE` and `TIMESTAMP WITH LOCAL TIME ZONE`.
 ///
 /// Don't use this type directly in your applications. This is public
 /// for types implementing `FromSql` and `ToSql` traits.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy)]
 pub struct Timestamp {
     pub year: i32,
     pub month: u32,
@@ -68,11 +131,7 @@ impl Timestamp {
 
     #[inline]
     pub fn and_tz_offset(&self, offset: i32) -> Timestamp {
-        let (tz_hour, tz_min) = if offset >= 0 {
-            (offset / 3600, (offset % 3600) / 60)
-        } else {
-            (-offset / 3600, (-offset % 3600) / 60)
-        };
+        let (tz_hour, tz_min) = (offset / 3600, (offset % 3600) / 60);
         Timestamp {
             tz_hour_offset: tz_hour,
             tz_minute_offset: tz_min,
@@ -94,22 +153,305 @@ impl Timestamp {
     pub fn tz_offset(&self) -> i32 {
         self.tz_hour_offset * 3600 + self.tz_minute_offset * 60
     }
+
+    /// Formats the timestamp according to a C `strftime`-style format string.
+    ///
+    /// The supported specifiers mirror the `time` crate: `%Y` (year, zero-padded
+    /// to four digits), `%m`, `%d`, `%H`, `%M`, `%S`, `%y` (two-digit year),
+    /// `%j` (day of the year, `001`–`366`), `%I` (12-hour clock), `%p` (`AM`/`PM`),
+    /// `%f` (fractional seconds honoring `precision`), `%z` (tz offset as `+0845`),
+    /// `%:z` (tz offset as `+08:45`) and `%%` for a literal percent. Literal
+    /// characters are copied verbatim. An unknown specifier yields an error
+    /// rather than a panic.
+    pub fn strftime(&self, fmt: &str) -> Result<String, ParseError> {
+        let err = || ParseError::new("Timestamp");
+        let mut buf = String::new();
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                buf.push(c);
+                continue;
+            }
+            match chars.next().ok_or(err())? {
+                'Y' => buf.push_str(&format!("{:04}", self.year)),
+                'y' => buf.push_str(&format!("{:02}", (self.year % 100).abs())),
+                'm' => buf.push_str(&format!("{:02}", self.month)),
+                'd' => buf.push_str(&format!("{:02}", self.day)),
+                'H' => buf.push_str(&format!("{:02}", self.hour)),
+                'M' => buf.push_str(&format!("{:02}", self.minute)),
+                'S' => buf.push_str(&format!("{:02}", self.second)),
+                'j' => buf.push_str(&format!("{:03}", self.day_of_year()?)),
+                'I' => {
+                    let hour12 = match self.hour % 12 { 0 => 12, h => h };
+                    buf.push_str(&format!("{:02}", hour12));
+                },
+                'p' => buf.push_str(if self.hour < 12 { "AM" } else { "PM" }),
+                'f' => self.write_fraction(&mut buf),
+                'z' => self.write_tz_offset(&mut buf, false),
+                ':' => match chars.next().ok_or(err())? {
+                    'z' => self.write_tz_offset(&mut buf, true),
+                    _ => return Err(err()),
+                },
+                '%' => buf.push('%'),
+                _ => return Err(err()),
+            }
+        }
+        Ok(buf)
+    }
+
+    /// Alias for [`strftime`](Timestamp::strftime).
+    #[inline]
+    pub fn format(&self, fmt: &str) -> Result<String, ParseError> {
+        self.strftime(fmt)
+    }
+
+    /// Parses a `Timestamp` from `s` according to the `strftime`-style format
+    /// string `fmt`, using the same specifier set as [`strftime`](Timestamp::strftime)
+    /// (`%Y %m %d %H %M %S %f %z %p %I %y` etc.). Literal characters in `fmt`
+    /// must match `s` exactly; `%f` sets `precision` from the number of
+    /// fractional digits consumed and `%z` (matching `+0845`, `+08:45` or `Z`)
+    /// populates the tz offset and sets `with_tz`. Fails with `ParseError` when a literal doesn't
+    /// match or a numeric field is missing.
+    pub fn strptime(s: &str, fmt: &str) -> Result<Timestamp, ParseError> {
+        let err = || ParseError::new("Timestamp");
+        let mut scanner = Scanner::new(s);
+        let mut year: i64 = 0;
+        let mut month: u64 = 1;
+        let mut day: u64 = 1;
+        let mut hour: u64 = 0;
+        let mut minute: u64 = 0;
+        let mut second: u64 = 0;
+        let mut nanosecond: u64 = 0;
+        let mut precision: u32 = 0;
+        let mut tz_hour: i32 = 0;
+        let mut tz_min: i32 = 0;
+        let mut with_tz = false;
+        let mut twelve_hour = false;
+        let mut pm = false;
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                if scanner.char() != Some(c) {
+                    return Err(err());
+                }
+                scanner.next();
+                continue;
+            }
+            match chars.next().ok_or(err())? {
+                'Y' => year = read_digits_max(&mut scanner, 4).ok_or(err())? as i64,
+                'y' => {
+                    let v = read_digits_max(&mut scanner, 2).ok_or(err())?;
+                    year = if v < 69 { 2000 + v as i64 } else { 1900 + v as i64 };
+                },
+                'm' => month = read_digits_max(&mut scanner, 2).ok_or(err())?,
+                'd' => day = read_digits_max(&mut scanner, 2).ok_or(err())?,
+                'H' => hour = read_digits_max(&mut scanner, 2).ok_or(err())?,
+                'I' => {
+                    hour = read_digits_max(&mut scanner, 2).ok_or(err())?;
+                    twelve_hour = true;
+                },
+                'M' => minute = read_digits_max(&mut scanner, 2).ok_or(err())?,
+                'S' => second = read_digits_max(&mut scanner, 2).ok_or(err())?,
+                'f' => {
+                    nanosecond = scanner.read_digits().ok_or(err())?;
+                    let ndigit = scanner.ndigits();
+                    precision = ndigit;
+                    if ndigit < 9 {
+                        nanosecond *= 10u64.pow(9 - ndigit);
+                    } else if ndigit > 9 {
+                        nanosecond /= 10u64.pow(ndigit - 9);
+                        precision = 9;
+                    }
+                },
+                'p' => {
+                    match scanner.char() {
+                        Some('A') | Some('a') => pm = false,
+                        Some('P') | Some('p') => pm = true,
+                        _ => return Err(err()),
+                    }
+                    scanner.next();
+                    match scanner.char() {
+                        Some('M') | Some('m') => scanner.next(),
+                        _ => return Err(err()),
+                    }
+                },
+                'z' => match scanner.char() {
+                    Some('Z') => {
+                        scanner.next();
+                        with_tz = true;
+                    },
+                    Some('+') | Some('-') => {
+                        let minus = scanner.char() == Some('-');
+                        scanner.next();
+                        let mut h = scanner.read_digits().ok_or(err())? as i32;
+                        let m;
+                        if let Some(':') = scanner.char() {
+                            scanner.next();
+                            m = scanner.read_digits().ok_or(err())? as i32;
+                        } else {
+                            m = h % 100;
+                            h /= 100;
+                        }
+                        tz_hour = if minus { -h } else { h };
+                        tz_min = if minus { -m } else { m };
+                        with_tz = true;
+                    },
+                    _ => return Err(err()),
+                },
+                '%' => {
+                    if scanner.char() != Some('%') {
+                        return Err(err());
+                    }
+                    scanner.next();
+                },
+                _ => return Err(err()),
+            }
+        }
+        if scanner.char().is_some() {
+            return Err(err());
+        }
+        if twelve_hour {
+            if pm && hour != 12 {
+                hour += 12;
+            } else if !pm && hour == 12 {
+                hour = 0;
+            }
+        }
+        let mut ts = Timestamp::new(year as i32, month as u32, day as u32,
+                                    hour as u32, minute as u32, second as u32, nanosecond as u32);
+        ts.precision = precision as u8;
+        if with_tz {
+            ts = ts.and_tz_hm_offset(tz_hour, tz_min);
+        }
+        Ok(ts)
+    }
+
+    /// Day of the year, `1` for January 1st. Returns a `Timestamp` error when
+    /// `month` lies outside `1..=12`.
+    fn day_of_year(&self) -> Result<u32, ParseError> {
+        if self.month < 1 || self.month > 12 {
+            return Err(ParseError::new("Timestamp"));
+        }
+        let mut days = self.day;
+        for m in 0..(self.month as usize - 1) {
+            days += DAYS_IN_MONTH[m];
+        }
+        if self.month > 2 && is_leap_year(self.year) {
+            days += 1;
+        }
+        Ok(days)
+    }
+
+    /// Signed number of nanoseconds elapsed from `other` to `self`, normalizing
+    /// both operands to a UTC instant as in the ordering comparison. The result
+    /// is positive when `self` is later than `other`.
+    pub fn duration_since(&self, other: &Timestamp) -> i128 {
+        self.instant_nanos() - other.instant_nanos()
+    }
+
+    /// Returns the timestamp shifted forward by `delta` nanoseconds, carrying
+    /// across seconds, minutes, hours, days, months and years (leap years
+    /// included). The `precision` and timezone fields are preserved. Returns
+    /// `None` if the resulting date falls outside the representable range.
+    pub fn checked_add_nanos(&self, delta: i128) -> Option<Timestamp> {
+        const DAY_NANOS: i128 = 86_400 * 1_000_000_000;
+        let days = days_from_civil(self.year, self.month, self.day) as i128;
+        let time_of_day = (self.hour as i128 * 3600
+            + self.minute as i128 * 60
+            + self.second as i128) * 1_000_000_000
+            + self.nanosecond as i128;
+        let total = days.checked_mul(DAY_NANOS)?.checked_add(time_of_day)?.checked_add(delta)?;
+        let mut rem = total.rem_euclid(DAY_NANOS);
+        let day_number = i64::try_from(total.div_euclid(DAY_NANOS)).ok()?;
+        if day_number < days_from_civil(i32::MIN, 1, 1) || day_number > days_from_civil(i32::MAX, 12, 31) {
+            return None;
+        }
+        let (year, month, day) = civil_from_days(day_number);
+        let nanosecond = (rem % 1_000_000_000) as u32;
+        rem /= 1_000_000_000;
+        let second = (rem % 60) as u32;
+        rem /= 60;
+        let minute = (rem % 60) as u32;
+        rem /= 60;
+        let hour = rem as u32;
+        let mut ts = Timestamp::new(year, month, day, hour, minute, second, nanosecond);
+        ts.precision = self.precision;
+        ts.tz_hour_offset = self.tz_hour_offset;
+        ts.tz_minute_offset = self.tz_minute_offset;
+        ts.with_tz = self.with_tz;
+        Some(ts)
+    }
+
+    /// Returns the timestamp shifted backward by `delta` nanoseconds. See
+    /// [`checked_add_nanos`](Timestamp::checked_add_nanos).
+    pub fn checked_sub_nanos(&self, delta: i128) -> Option<Timestamp> {
+        delta.checked_neg().and_then(|d| self.checked_add_nanos(d))
+    }
+
+    /// Writes the fractional seconds (without the leading dot) honoring
+    /// `precision`, matching the digits emitted by `Display`.
+    fn write_fraction(&self, buf: &mut String) {
+        match self.precision {
+            1 => buf.push_str(&format!("{:01}", self.nanosecond / 100000000)),
+            2 => buf.push_str(&format!("{:02}", self.nanosecond / 10000000)),
+            3 => buf.push_str(&format!("{:03}", self.nanosecond / 1000000)),
+            4 => buf.push_str(&format!("{:04}", self.nanosecond / 100000)),
+            5 => buf.push_str(&format!("{:05}", self.nanosecond / 10000)),
+            6 => buf.push_str(&format!("{:06}", self.nanosecond / 1000)),
+            7 => buf.push_str(&format!("{:07}", self.nanosecond / 100)),
+            8 => buf.push_str(&format!("{:08}", self.nanosecond / 10)),
+            9 => buf.push_str(&format!("{:09}", self.nanosecond)),
+            _ => (),
+        }
+    }
+
+    /// Normalizes the timestamp to a UTC instant expressed as signed nanoseconds
+    /// from the Unix epoch. When `with_tz` is set the offset is subtracted to
+    /// reach UTC; otherwise the value is treated as a wall-clock instant with a
+    /// zero offset.
+    fn instant_nanos(&self) -> i128 {
+        let days = days_from_civil(self.year, self.month, self.day) as i128;
+        let mut seconds = days * 86400
+            + self.hour as i128 * 3600
+            + self.minute as i128 * 60
+            + self.second as i128;
+        if self.with_tz {
+            seconds -= self.tz_offset() as i128;
+        }
+        seconds * 1_000_000_000 + self.nanosecond as i128
+    }
+
+    /// Builds a UTC timestamp (with `with_tz` set and a zero offset) from a
+    /// signed number of nanoseconds since the Unix epoch. Inverse of
+    /// [`instant_nanos`](Timestamp::instant_nanos) for offset-zero values.
+    fn from_epoch_nanos(nanos: i128) -> Option<Timestamp> {
+        let base = Timestamp::new(1970, 1, 1, 0, 0, 0, 0).and_tz_hm_offset(0, 0);
+        let mut ts = base.checked_add_nanos(nanos)?;
+        ts.precision = 9;
+        Some(ts)
+    }
+
+    /// Writes the timezone offset built from `tz_hour_offset`/`tz_minute_offset`,
+    /// either as `+0845` (`colon == false`) or `+08:45` (`colon == true`).
+    fn write_tz_offset(&self, buf: &mut String, colon: bool) {
+        let sign = if self.tz_hour_offset < 0 || self.tz_minute_offset < 0 { '-' } else { '+' };
+        let hour = self.tz_hour_offset.abs();
+        let minute = self.tz_minute_offset.abs();
+        if colon {
+            buf.push_str(&format!("{}{:02}:{:02}", sign, hour, minute));
+        } else {
+            buf.push_str(&format!("{}{:02}{:02}", sign, hour, minute));
+        }
+    }
 }
 
 impl fmt::Display for Timestamp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}-{:02}-{:02} {:02}:{:02}:{:02}", self.year, self.month, self.day, self.hour, self.minute, self.second)?;
-        match self.precision {
-            1 => write!(f, ".{:01}", self.nanosecond / 100000000)?,
-            2 => write!(f, ".{:02}", self.nanosecond / 10000000)?,
-            3 => write!(f, ".{:03}", self.nanosecond / 1000000)?,
-            4 => write!(f, ".{:04}", self.nanosecond / 100000)?,
-            5 => write!(f, ".{:05}", self.nanosecond / 10000)?,
-            6 => write!(f, ".{:06}", self.nanosecond / 1000)?,
-            7 => write!(f, ".{:07}", self.nanosecond / 100)?,
-            8 => write!(f, ".{:08}", self.nanosecond / 10)?,
-            9 => write!(f, ".{:09}", self.nanosecond)?,
-            _ => (),
+        let mut fraction = String::new();
+        self.write_fraction(&mut fraction);
+        if !fraction.is_empty() {
+            write!(f, ".{}", fraction)?;
         }
         if self.with_tz {
             write!(f, " {:+03}:{:02}",
@@ -119,6 +461,37 @@ impl fmt::Display for Timestamp {
     }
 }
 
+/// Comparison and equality are *instant-wise*: each value is normalized to a
+/// UTC instant (see [`Timestamp::tz_offset`]) before comparing, so two
+/// timestamps denoting the same moment written with different offsets compare
+/// as equal and sort together. `PartialEq`/`Eq` and `Ord` agree, so the type
+/// is a well-behaved `BTreeMap`/`HashSet` key.
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Timestamp) -> bool {
+        self.instant_nanos() == other.instant_nanos()
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instant_nanos().hash(state);
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Timestamp) -> Ordering {
+        self.instant_nanos().cmp(&other.instant_nanos())
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Timestamp) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl str::FromStr for Timestamp {
     type Err = ParseError;
 
@@ -246,6 +619,172 @@ impl str::FromStr for Timestamp {
     }
 }
 
+/// Conversions to and from the [`chrono`](https://crates.io/crates/chrono) crate.
+#[cfg(feature = "chrono")]
+mod chrono_conv {
+    use std::convert::TryFrom;
+
+    use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+
+    use super::Timestamp;
+    use ParseError;
+
+    impl TryFrom<Timestamp> for NaiveDateTime {
+        type Error = ParseError;
+
+        fn try_from(ts: Timestamp) -> Result<NaiveDateTime, ParseError> {
+            let err = || ParseError::new("Timestamp");
+            NaiveDate::from_ymd_opt(ts.year, ts.month, ts.day)
+                .and_then(|d| d.and_hms_nano_opt(ts.hour, ts.minute, ts.second, ts.nanosecond))
+                .ok_or_else(err)
+        }
+    }
+
+    impl TryFrom<Timestamp> for DateTime<FixedOffset> {
+        type Error = ParseError;
+
+        fn try_from(ts: Timestamp) -> Result<DateTime<FixedOffset>, ParseError> {
+            let err = || ParseError::new("Timestamp");
+            let naive = NaiveDateTime::try_from(ts)?;
+            let offset = FixedOffset::east_opt(ts.tz_offset()).ok_or_else(err)?;
+            offset.from_local_datetime(&naive).single().ok_or_else(err)
+        }
+    }
+
+    impl From<NaiveDateTime> for Timestamp {
+        fn from(dt: NaiveDateTime) -> Timestamp {
+            let mut ts = Timestamp::new(dt.year(), dt.month(), dt.day(),
+                                        dt.hour(), dt.minute(), dt.second(), dt.nanosecond());
+            ts.precision = 9;
+            ts
+        }
+    }
+
+    impl From<DateTime<FixedOffset>> for Timestamp {
+        fn from(dt: DateTime<FixedOffset>) -> Timestamp {
+            let ts = Timestamp::from(dt.naive_local());
+            ts.and_tz_offset(dt.offset().local_minus_utc())
+        }
+    }
+}
+
+/// Conversions to and from the [`time`](https://crates.io/crates/time) crate.
+#[cfg(feature = "time")]
+mod time_conv {
+    use std::convert::TryFrom;
+
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    use super::Timestamp;
+    use ParseError;
+
+    impl TryFrom<Timestamp> for PrimitiveDateTime {
+        type Error = ParseError;
+
+        fn try_from(ts: Timestamp) -> Result<PrimitiveDateTime, ParseError> {
+            let err = || ParseError::new("Timestamp");
+            let month = Month::try_from(ts.month as u8).map_err(|_| err())?;
+            let date = Date::from_calendar_date(ts.year, month, ts.day as u8).map_err(|_| err())?;
+            let time = Time::from_hms_nano(ts.hour as u8, ts.minute as u8, ts.second as u8,
+                                           ts.nanosecond).map_err(|_| err())?;
+            Ok(PrimitiveDateTime::new(date, time))
+        }
+    }
+
+    impl TryFrom<Timestamp> for OffsetDateTime {
+        type Error = ParseError;
+
+        fn try_from(ts: Timestamp) -> Result<OffsetDateTime, ParseError> {
+            let err = || ParseError::new("Timestamp");
+            let pdt = PrimitiveDateTime::try_from(ts)?;
+            let offset = UtcOffset::from_whole_seconds(ts.tz_offset()).map_err(|_| err())?;
+            Ok(pdt.assume_offset(offset))
+        }
+    }
+
+    impl From<PrimitiveDateTime> for Timestamp {
+        fn from(dt: PrimitiveDateTime) -> Timestamp {
+            let mut ts = Timestamp::new(dt.year(), dt.month() as u32, dt.day() as u32,
+                                        dt.hour() as u32, dt.minute() as u32, dt.second() as u32,
+                                        dt.nanosecond());
+            ts.precision = 9;
+            ts
+        }
+    }
+
+    impl From<OffsetDateTime> for Timestamp {
+        fn from(dt: OffsetDateTime) -> Timestamp {
+            let mut ts = Timestamp::new(dt.year(), dt.month() as u32, dt.day() as u32,
+                                        dt.hour() as u32, dt.minute() as u32, dt.second() as u32,
+                                        dt.nanosecond());
+            ts.precision = 9;
+            ts.and_tz_offset(dt.offset().whole_seconds())
+        }
+    }
+}
+
+/// `serde` support for `Timestamp`.
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use serde::de::{self, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Timestamp;
+
+    /// Serializes to the string produced by `Display`
+    /// (`YYYY-MM-DD HH:MM:SS[.fff] [+HH:MM]`), so `with_tz` values carry a
+    /// ` +HH:MM` suffix and fractional digits follow `precision`.
+    impl Serialize for Timestamp {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    struct TimestampVisitor;
+
+    impl<'de> Visitor<'de> for TimestampVisitor {
+        type Value = Timestamp;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an ISO-8601 timestamp string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Timestamp, E> {
+            Timestamp::from_str(v).map_err(de::Error::custom)
+        }
+    }
+
+    /// Deserializes by routing the string through the existing `FromStr`.
+    impl<'de> Deserialize<'de> for Timestamp {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+            deserializer.deserialize_str(TimestampVisitor)
+        }
+    }
+
+    /// `#[serde(with = "...")]`-compatible helpers representing a `Timestamp` as
+    /// a signed number of nanoseconds since the Unix epoch, an alternative to
+    /// the default string form.
+    pub mod epoch_nanos {
+        use serde::de::{self, Deserialize};
+        use serde::{Deserializer, Serializer};
+
+        use super::super::Timestamp;
+
+        pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_i128(ts.instant_nanos())
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+            let nanos = i128::deserialize(deserializer)?;
+            Timestamp::from_epoch_nanos(nanos)
+                .ok_or_else(|| de::Error::custom("timestamp out of range"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,4 +877,103 @@ mod tests {
         ts.year = -123;
         assert_eq!("-123-03-04 05:06:07.123 -08:45".parse(), Ok(ts));
     }
+
+    #[test]
+    fn tz_offset_sign() {
+        let ts = Timestamp::new(2012, 3, 4, 5, 6, 7, 0).and_tz_offset(-18000);
+        assert_eq!(ts.tz_hour_offset, -5);
+        assert_eq!(ts.tz_minute_offset, 0);
+        assert_eq!(ts.tz_offset(), -18000);
+        assert_eq!(ts.to_string(), "2012-03-04 05:06:07 -05:00");
+        let ts = Timestamp::new(2012, 3, 4, 5, 6, 7, 0).and_tz_offset(-19800);
+        assert_eq!(ts.tz_hour_offset, -5);
+        assert_eq!(ts.tz_minute_offset, -30);
+        assert_eq!(ts.tz_offset(), -19800);
+    }
+
+    #[test]
+    fn strftime() {
+        let ts = Timestamp::new(2012, 3, 4, 13, 6, 7, 890123456).and_tz_hm_offset(8, 45);
+        assert_eq!(ts.strftime("%Y-%m-%d").unwrap(), "2012-03-04");
+        assert_eq!(ts.strftime("%y").unwrap(), "12");
+        assert_eq!(ts.strftime("%H:%M:%S").unwrap(), "13:06:07");
+        assert_eq!(ts.strftime("%I%p").unwrap(), "01PM");
+        assert_eq!(ts.strftime("%j").unwrap(), "064");
+        assert_eq!(ts.strftime("%z").unwrap(), "+0845");
+        assert_eq!(ts.strftime("%:z").unwrap(), "+08:45");
+        assert_eq!(ts.strftime("100%%").unwrap(), "100%");
+        let mut ts = ts;
+        ts.precision = 3;
+        assert_eq!(ts.strftime("%H:%M:%S.%f").unwrap(), "13:06:07.890");
+        ts.tz_hour_offset = -8; ts.tz_minute_offset = -45;
+        assert_eq!(ts.strftime("%:z").unwrap(), "-08:45");
+        assert!(ts.strftime("%Q").is_err());
+        // An out-of-range month yields an error instead of panicking on %j.
+        let mut bad = Timestamp::new(2012, 13, 4, 0, 0, 0, 0);
+        assert!(bad.strftime("%j").is_err());
+        bad.month = 0;
+        assert!(bad.strftime("%j").is_err());
+    }
+
+    #[test]
+    fn strptime() {
+        let mut ts = Timestamp::new(2012, 3, 4, 5, 6, 7, 0);
+        assert_eq!(Timestamp::strptime("2012-03-04 05:06:07", "%Y-%m-%d %H:%M:%S"), Ok(ts));
+        assert_eq!(Timestamp::strptime("20120304050607", "%Y%m%d%H%M%S"), Ok(ts));
+        ts.nanosecond = 123000000; ts.precision = 3;
+        assert_eq!(Timestamp::strptime("2012-03-04 05:06:07.123", "%Y-%m-%d %H:%M:%S.%f"), Ok(ts));
+        ts.nanosecond = 0; ts.precision = 0;
+        assert_eq!(Timestamp::strptime("05:06:07 PM 2012", "%I:%M:%S %p %Y"),
+                   Ok(Timestamp::new(2012, 1, 1, 17, 6, 7, 0)));
+        ts = ts.and_tz_hm_offset(8, 45);
+        assert_eq!(Timestamp::strptime("2012-03-04 05:06:07 +0845", "%Y-%m-%d %H:%M:%S %z"), Ok(ts));
+        assert_eq!(Timestamp::strptime("2012-03-04 05:06:07 +08:45", "%Y-%m-%d %H:%M:%S %z"), Ok(ts));
+        ts = ts.and_tz_hm_offset(-8, -45);
+        assert_eq!(Timestamp::strptime("2012-03-04 05:06:07 -08:45", "%Y-%m-%d %H:%M:%S %z"), Ok(ts));
+        assert_eq!(Timestamp::strptime("12/03/04", "%y/%m/%d"),
+                   Ok(Timestamp::new(2012, 3, 4, 0, 0, 0, 0)));
+        assert!(Timestamp::strptime("2012x03", "%Y-%m").is_err());
+        // Leftover input after the last specifier is rejected.
+        assert!(Timestamp::strptime("2012-03-04junk", "%Y-%m-%d").is_err());
+    }
+
+    #[test]
+    fn ordering() {
+        let a = Timestamp::new(2012, 3, 4, 5, 6, 7, 0);
+        let b = Timestamp::new(2012, 3, 4, 6, 6, 7, 0);
+        assert!(a < b);
+        // Same instant written in two timezones: 05:06 +00:00 == 06:06 +01:00.
+        let utc = Timestamp::new(2012, 3, 4, 5, 6, 7, 0).and_tz_hm_offset(0, 0);
+        let plus1 = Timestamp::new(2012, 3, 4, 6, 6, 7, 0).and_tz_hm_offset(1, 0);
+        assert_eq!(utc.cmp(&plus1), Ordering::Equal);
+        // Equality is instant-wise and agrees with Ord.
+        assert_eq!(utc, plus1);
+        // Across a day boundary.
+        let late = Timestamp::new(2012, 3, 4, 23, 0, 0, 0);
+        let early = Timestamp::new(2012, 3, 5, 1, 0, 0, 0);
+        assert!(late < early);
+    }
+
+    #[test]
+    fn duration() {
+        let a = Timestamp::new(2012, 3, 4, 5, 6, 7, 0);
+        let b = Timestamp::new(2012, 3, 4, 5, 6, 8, 500000000);
+        assert_eq!(b.duration_since(&a), 1_500_000_000);
+        assert_eq!(a.duration_since(&b), -1_500_000_000);
+
+        let mut ts = Timestamp::new(2012, 2, 28, 23, 59, 59, 0);
+        ts.precision = 3;
+        // Carry across a leap-year month boundary into Feb 29.
+        let next = ts.checked_add_nanos(1_000_000_000).unwrap();
+        let mut expected = Timestamp::new(2012, 2, 29, 0, 0, 0, 0);
+        expected.precision = 3;
+        assert_eq!(next, expected);
+        // Crossing a year boundary backward.
+        let prev = Timestamp::new(2013, 1, 1, 0, 0, 0, 0)
+            .checked_sub_nanos(1_000_000_000).unwrap();
+        assert_eq!(prev, Timestamp::new(2012, 12, 31, 23, 59, 59, 0));
+        // Round-trip add/sub.
+        assert_eq!(a.checked_add_nanos(123_456_789).unwrap()
+                    .checked_sub_nanos(123_456_789).unwrap(), a);
+    }
 }